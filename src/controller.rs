@@ -0,0 +1,63 @@
+//! Bot players. A `PlayerController` decides what a player does each turn
+//! instead of a human typing it in; `Game` falls back to the `Frontend`
+//! for any player that doesn't have one (see `Player::controller`).
+
+use crate::game::{AnsweredQuestion, PlayerID};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+/// What a bot needs to know to write a question. `RandomBot` ignores it,
+/// but it's here for controllers that want to vary behavior by round.
+pub struct QuestionCtx {
+    #[allow(dead_code)]
+    pub round: u32,
+}
+
+pub trait PlayerController {
+    fn write_question(&mut self, ctx: &QuestionCtx) -> String;
+    fn answer(&mut self, prompt: &str) -> String;
+    fn guess(&mut self, answered: &AnsweredQuestion, candidates: &[PlayerID]) -> PlayerID;
+}
+
+const QUESTION_BANK: &[&str] = &[
+    "What's the most trouble you've ever gotten into?",
+    "What's a skill you wish you had?",
+    "What's your go-to karaoke song?",
+    "What's the weirdest food you've ever eaten?",
+];
+
+const ANSWER_BANK: &[&str] = &[
+    "Honestly, longer than I'd like to admit.",
+    "Probably juggling, but badly.",
+    "Anything by ABBA, unironically.",
+    "Something involving durian, once.",
+];
+
+/// Picks uniformly at random among the options it's given. Deterministic
+/// given the same seed, so an all-bot game can be replayed exactly.
+pub struct RandomBot {
+    rng: StdRng,
+}
+
+impl RandomBot {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl PlayerController for RandomBot {
+    fn write_question(&mut self, _ctx: &QuestionCtx) -> String {
+        QUESTION_BANK.choose(&mut self.rng).unwrap().to_string()
+    }
+
+    fn answer(&mut self, _prompt: &str) -> String {
+        ANSWER_BANK.choose(&mut self.rng).unwrap().to_string()
+    }
+
+    fn guess(&mut self, _answered: &AnsweredQuestion, candidates: &[PlayerID]) -> PlayerID {
+        *candidates.choose(&mut self.rng).unwrap()
+    }
+}
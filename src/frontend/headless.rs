@@ -0,0 +1,45 @@
+//! A frontend for all-bot games: there's nobody to prompt, so every
+//! `prompt_*` call would be a bug in the caller. `show` just prints the
+//! event stream, which is what you want when running games headless to
+//! test or balance the scoring rules.
+
+use super::{Frontend, GameEvent, GuessCandidate};
+use crate::game::{Player, PlayerID};
+
+pub struct HeadlessFrontend;
+
+impl Frontend for HeadlessFrontend {
+    fn prompt_question(&mut self, _author: &Player) -> String {
+        unreachable!("HeadlessFrontend is only valid when every player has a PlayerController")
+    }
+
+    fn prompt_answer(&mut self, _player: &Player, _question: &str) -> String {
+        unreachable!("HeadlessFrontend is only valid when every player has a PlayerController")
+    }
+
+    fn prompt_guess(&mut self, _guesser: &Player, _question: &str, _answer: &str, _candidates: &[&Player]) -> PlayerID {
+        unreachable!("HeadlessFrontend is only valid when every player has a PlayerController")
+    }
+
+    fn prompt_bluff_guess(&mut self, _guesser: &Player, _question: &str, _candidates: &[GuessCandidate]) -> PlayerID {
+        unreachable!("HeadlessFrontend is only valid when every player has a PlayerController")
+    }
+
+    fn show(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::RoundStarted { round, total_rounds } => {
+                println!("-- round {}/{} --", round, total_rounds);
+            }
+            GameEvent::AnswerResults { answerer_nickname, scores, .. } => {
+                println!("{} answered; scores: {:?}", answerer_nickname, scores);
+            }
+            GameEvent::FinalScores(scores) => {
+                println!("-- final scores: {:?} --", scores);
+            }
+            GameEvent::QuestionsCollected
+            | GameEvent::AnswersCollected
+            | GameEvent::GuessResult { .. }
+            | GameEvent::RoundComplete => {}
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Everything `Game` needs from the outside world: asking players for
+//! input, and telling them what happened. `Game` is generic over
+//! `Frontend`, so the same turn-by-turn engine logic can be driven by a
+//! terminal, a network connection, or a test harness.
+
+pub mod headless;
+pub mod terminal;
+
+use crate::game::{Player, PlayerID};
+
+/// Something noteworthy `Game` wants a frontend to display. Frontends are
+/// free to ignore events they don't care about.
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    RoundStarted { round: u32, total_rounds: u32 },
+    QuestionsCollected,
+    AnswersCollected,
+    GuessResult { guesser_nickname: String, correct: bool, points: u32 },
+    /// All guesses for one answered question are in; `scores` is the
+    /// scoreboard as it stands right after this question was resolved.
+    AnswerResults {
+        answerer: PlayerID,
+        answerer_nickname: String,
+        scores: Vec<(String, u32)>,
+    },
+    RoundComplete,
+    FinalScores(Vec<(String, u32)>),
+}
+
+/// One option a guesser can pick from in bluff mode: a candidate author
+/// and the (possibly fake) answer text attributed to them.
+pub struct GuessCandidate<'a> {
+    pub player: &'a Player,
+    pub answer: &'a str,
+}
+
+/// How `Game` talks to players. Implement this once per transport
+/// (terminal, network socket, ...) and the engine logic doesn't need to
+/// change.
+pub trait Frontend {
+    /// Ask `author` to write a question for someone else to answer.
+    fn prompt_question(&mut self, author: &Player) -> String;
+
+    /// Ask `player` to answer `question`. Used both for the question
+    /// `player` was assigned and, in decoy mode, for writing a fake answer
+    /// to a question assigned to someone else.
+    fn prompt_answer(&mut self, player: &Player, question: &str) -> String;
+
+    /// Ask `guesser` to pick who they think wrote `answer` (a response to
+    /// `question`), out of `candidates`.
+    fn prompt_guess(
+        &mut self,
+        guesser: &Player,
+        question: &str,
+        answer: &str,
+        candidates: &[&Player],
+    ) -> PlayerID;
+
+    /// Like `prompt_guess`, but used in bluff mode: each candidate has
+    /// their own (real or fake) answer text, and `guesser` is picking the
+    /// genuine one rather than matching a single shared answer to a name.
+    fn prompt_bluff_guess(
+        &mut self,
+        guesser: &Player,
+        question: &str,
+        candidates: &[GuessCandidate],
+    ) -> PlayerID;
+
+    /// Notify the frontend that something happened.
+    fn show(&mut self, event: &GameEvent);
+}
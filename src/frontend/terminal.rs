@@ -0,0 +1,184 @@
+//! Pass-the-terminal frontend: the original gameplay, now implemented
+//! against the `Frontend` trait instead of baked into `Game` itself.
+
+use super::{Frontend, GameEvent, GuessCandidate};
+use crate::game::{Player, PlayerID};
+use std::io::{self, Write};
+
+macro_rules! flushed_print {
+    ($($arg:tt)*) => {
+        print!(
+            "{}",
+            format_args!($($arg)*),
+        );
+        io::stdout().flush().unwrap();
+    }
+}
+
+fn clear_screen() {
+    flushed_print!("\x1B[2J\x1B[1;1H");
+}
+
+fn wait_for_enter() {
+    read_line();
+}
+
+fn read_line() -> String {
+    let mut buf = String::new();
+    io::stdin().read_line(&mut buf).unwrap();
+    // remove newline
+    buf.pop();
+    buf
+}
+
+/// Hands the device to each player in turn, clearing the screen so nobody
+/// sees anyone else's answers.
+pub struct TerminalFrontend;
+
+impl TerminalFrontend {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn summon(&self, player: &Player) {
+        clear_screen();
+        flushed_print!("=> {}, press <ENTER>", player.nickname);
+        wait_for_enter();
+        clear_screen();
+    }
+}
+
+impl Frontend for TerminalFrontend {
+    fn prompt_question(&mut self, author: &Player) -> String {
+        self.summon(author);
+        flushed_print!("Enter a question: ");
+        let prompt = read_line();
+        clear_screen();
+        prompt
+    }
+
+    fn prompt_answer(&mut self, player: &Player, question: &str) -> String {
+        self.summon(player);
+        println!("=> Answer this question:\n\t{}", question);
+        flushed_print!("Answer: ");
+        let answer = read_line();
+        clear_screen();
+        answer
+    }
+
+    fn prompt_guess(
+        &mut self,
+        guesser: &Player,
+        question: &str,
+        answer: &str,
+        candidates: &[&Player],
+    ) -> PlayerID {
+        flushed_print!("=> {}, press enter to start guessing", guesser.nickname);
+        wait_for_enter();
+        clear_screen();
+        println!("=> question:\n\t{}", question);
+        println!("=> response:\n\t{}", answer);
+        println!();
+        println!("=> ids:");
+        let mut sorted_candidates: Vec<_> = candidates.to_vec();
+        sorted_candidates.sort_by_key(|c| c.id);
+        for candidate in sorted_candidates {
+            println!("{:2}: {}", candidate.id, candidate.nickname);
+        }
+        println!(
+            "=> {}, who do you think wrote this answer? Enter an ID from above.",
+            guesser.nickname
+        );
+        let candidate_ids: Vec<PlayerID> = candidates.iter().map(|p| p.id).collect();
+        let guess = loop {
+            flushed_print!("{}'s guess: ", guesser.nickname);
+            let input = read_line();
+            match input.parse() {
+                Ok(id) if candidate_ids.contains(&id) => break id,
+                _ => println!("=> You need to enter an ID from the list."),
+            }
+        };
+        clear_screen();
+        guess
+    }
+
+    fn prompt_bluff_guess(
+        &mut self,
+        guesser: &Player,
+        question: &str,
+        candidates: &[GuessCandidate],
+    ) -> PlayerID {
+        flushed_print!("=> {}, press enter to start guessing", guesser.nickname);
+        wait_for_enter();
+        clear_screen();
+        println!("=> question:\n\t{}", question);
+        println!();
+        println!("=> candidate answers:");
+        let mut sorted_candidates: Vec<_> = candidates.iter().collect();
+        sorted_candidates.sort_by_key(|c| c.player.id);
+        for candidate in &sorted_candidates {
+            println!("{:2}: {}", candidate.player.id, candidate.answer);
+        }
+        println!(
+            "=> {}, which of these is the genuine answer? Enter the ID next to it.",
+            guesser.nickname
+        );
+        let candidate_ids: Vec<PlayerID> = candidates.iter().map(|c| c.player.id).collect();
+        let guess = loop {
+            flushed_print!("{}'s guess: ", guesser.nickname);
+            let input = read_line();
+            match input.parse() {
+                Ok(id) if candidate_ids.contains(&id) => break id,
+                _ => println!("=> You need to enter an ID from the list."),
+            }
+        };
+        clear_screen();
+        guess
+    }
+
+    fn show(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::RoundStarted { round, .. } => {
+                clear_screen();
+                flushed_print!("=> Press <ENTER> to start round {}", round);
+                wait_for_enter();
+            }
+            GameEvent::QuestionsCollected => {
+                flushed_print!("=> Press <ENTER> to start answering");
+                wait_for_enter();
+            }
+            GameEvent::AnswersCollected => {
+                clear_screen();
+            }
+            GameEvent::GuessResult {
+                guesser_nickname,
+                correct,
+                points,
+            } => {
+                if *correct {
+                    println!("=> {} was CORRECT. +{} points.", guesser_nickname, points);
+                } else {
+                    println!("=> {} was INCORRECT.", guesser_nickname);
+                }
+            }
+            GameEvent::AnswerResults { answerer_nickname, .. } => {
+                flushed_print!("=> Guessing done. Press <ENTER> to see the results.");
+                wait_for_enter();
+                println!("=> **{}** was the one who answered the question.", answerer_nickname);
+            }
+            GameEvent::RoundComplete => {
+                flushed_print!("=> Press <ENTER> continue.");
+                wait_for_enter();
+            }
+            GameEvent::FinalScores(scores) => {
+                clear_screen();
+                let max_nickname_len = scores.iter().map(|(n, _)| n.len()).max().unwrap_or(0);
+                println!("=> Final scores:");
+                for (nickname, score) in scores {
+                    let padded = format!("{}{}", nickname, " ".repeat(max_nickname_len - nickname.len()));
+                    println!("{}: {} points", padded, score);
+                }
+            }
+        }
+    }
+}
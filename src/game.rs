@@ -0,0 +1,777 @@
+use crate::controller::{PlayerController, QuestionCtx};
+use crate::frontend::{Frontend, GameEvent, GuessCandidate};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::{fs, io};
+
+pub type PlayerID = u8;
+
+#[derive(Serialize, Deserialize)]
+pub struct Player {
+    pub id: PlayerID,
+    pub nickname: String,
+    pub score: u32,
+    question_pending_answer: Option<Question>,
+    /// `None` means a human, driven through `Game`'s `Frontend`; `Some`
+    /// means a bot, driven through this controller instead. Controllers
+    /// aren't serializable, so a loaded save always comes back with
+    /// `None` here, even for players that were bots when it was saved.
+    #[serde(skip)]
+    controller: Option<Box<dyn PlayerController>>,
+}
+
+impl std::fmt::Debug for Player {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Player")
+            .field("id", &self.id)
+            .field("nickname", &self.nickname)
+            .field("score", &self.score)
+            .field("question_pending_answer", &self.question_pending_answer)
+            .field("is_bot", &self.controller.is_some())
+            .finish()
+    }
+}
+
+impl Player {
+    fn new(id: PlayerID, nickname: String, controller: Option<Box<dyn PlayerController>>) -> Self {
+        Self {
+            id,
+            nickname,
+            score: 0,
+            question_pending_answer: None,
+            controller,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Question {
+    pub author: PlayerID,
+    pub prompt: String,
+}
+
+impl Question {
+    fn new(author: PlayerID, prompt: String) -> Self {
+        Self { author, prompt }
+    }
+
+    fn respond(self, answered_by: PlayerID, answer: String) -> AnsweredQuestion {
+        AnsweredQuestion {
+            question: self,
+            answered_by,
+            answer,
+            decoys: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnsweredQuestion {
+    pub question: Question,
+    pub answered_by: PlayerID,
+    pub answer: String,
+    /// Fake answers to the same question, each attributed to the player
+    /// who wrote it. Only populated when the game's decoy mode is on.
+    pub decoys: Vec<(PlayerID, String)>,
+}
+
+/// Tunable scoring knobs for a `Game`. The defaults reproduce the
+/// original flat "+1 per correct guess" behavior.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ScoringRules {
+    /// Points a guesser earns for correctly identifying the answerer.
+    pub correct_guess_points: u32,
+    /// Extra points the asker (the question's author) earns if they're
+    /// the only one who guesses correctly about their own question.
+    pub lone_asker_bonus: u32,
+    /// Extra points added to `correct_guess_points` per round already
+    /// played, so later rounds are worth more.
+    pub round_escalation: u32,
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self {
+            correct_guess_points: 1,
+            lone_asker_bonus: 0,
+            round_escalation: 0,
+        }
+    }
+}
+
+impl ScoringRules {
+    fn points_for_round(&self, round: u32) -> u32 {
+        self.correct_guess_points + self.round_escalation * round.saturating_sub(1)
+    }
+}
+
+/// Whether a guess about who wrote an answer was right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuessOutcome {
+    Correct,
+    Incorrect,
+}
+
+/// Where a `Game` currently is in a round. `advance` walks a game through
+/// these in order, looping `CollectingQuestions..=RoundComplete` once per
+/// round until `total_rounds` is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GamePhase {
+    Lobby,
+    CollectingQuestions,
+    CollectingAnswers,
+    /// Guessing the author of one answered question. `Game` tracks which
+    /// one internally; callers call `record_guess` once per guesser, then
+    /// `advance` to move on to the next answered question (or out of
+    /// `Guessing` once none are left).
+    Guessing,
+    RoundComplete,
+    Finished,
+}
+
+/// One entry in a game's transcript: every prompt, answer, and guess, plus
+/// each round's score deltas, in the order they happened.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TranscriptEntry {
+    Question { round: u32, author: PlayerID, prompt: String },
+    Answer { round: u32, player: PlayerID, answer: String },
+    Decoy { round: u32, author: PlayerID, answer: String },
+    Guess { round: u32, guesser: PlayerID, guessed: PlayerID, correct: bool },
+    RoundScores { round: u32, deltas: Vec<(String, i64)> },
+}
+
+/// Borrowed view of a `Game`'s serializable state, written out by `save`.
+/// `frontend` and `rng` aren't part of it: a resumed game gets a fresh
+/// frontend from the caller and a fresh, unseeded RNG.
+#[derive(Serialize)]
+struct GameSnapshot<'a> {
+    next_player_id: PlayerID,
+    players: &'a HashMap<PlayerID, Player>,
+    phase: GamePhase,
+    round: u32,
+    total_rounds: u32,
+    pairs: &'a HashMap<PlayerID, PlayerID>,
+    pending_answers: &'a Vec<AnsweredQuestion>,
+    guess_queue: &'a VecDeque<AnsweredQuestion>,
+    current_answer: &'a Option<AnsweredQuestion>,
+    scoring: ScoringRules,
+    decoy_mode: bool,
+    round_start_scores: &'a HashMap<PlayerID, u32>,
+}
+
+/// Owned counterpart of `GameSnapshot`, read back in by `load`.
+#[derive(Deserialize)]
+struct OwnedGameSnapshot {
+    next_player_id: PlayerID,
+    players: HashMap<PlayerID, Player>,
+    phase: GamePhase,
+    round: u32,
+    total_rounds: u32,
+    pairs: HashMap<PlayerID, PlayerID>,
+    pending_answers: Vec<AnsweredQuestion>,
+    guess_queue: VecDeque<AnsweredQuestion>,
+    current_answer: Option<AnsweredQuestion>,
+    scoring: ScoringRules,
+    decoy_mode: bool,
+    round_start_scores: HashMap<PlayerID, u32>,
+}
+
+#[derive(Debug)]
+pub struct Game<F: Frontend> {
+    next_player_id: PlayerID,
+    players: HashMap<PlayerID, Player>,
+    frontend: F,
+    phase: GamePhase,
+    round: u32,
+    total_rounds: u32,
+    /// author -> who is answering their question this round
+    pairs: HashMap<PlayerID, PlayerID>,
+    pending_answers: Vec<AnsweredQuestion>,
+    guess_queue: VecDeque<AnsweredQuestion>,
+    current_answer: Option<AnsweredQuestion>,
+    rng: StdRng,
+    autosave_path: Option<String>,
+    transcript: Vec<TranscriptEntry>,
+    round_start_scores: HashMap<PlayerID, u32>,
+    scoring: ScoringRules,
+    decoy_mode: bool,
+    /// Guesses made about the question currently being guessed, so
+    /// `begin_next_guess` can award the lone-asker bonus once they're all
+    /// in. Cleared each time a new question starts being guessed.
+    current_question_guesses: Vec<(PlayerID, GuessOutcome)>,
+}
+
+impl<F: Frontend> Game<F> {
+    pub fn new(frontend: F) -> Self {
+        Self::with_rng(frontend, StdRng::from_entropy())
+    }
+
+    /// Like `new`, but `generate_player_pairs` and guesser ordering are
+    /// drawn from a seeded RNG, so the whole game is reproducible. Combined
+    /// with `RandomBot`, this lets a full game be replayed exactly.
+    pub fn new_with_seed(frontend: F, seed: u64) -> Self {
+        Self::with_rng(frontend, StdRng::seed_from_u64(seed))
+    }
+
+    fn with_rng(frontend: F, rng: StdRng) -> Self {
+        Self {
+            next_player_id: 0,
+            players: HashMap::new(),
+            frontend,
+            phase: GamePhase::Lobby,
+            round: 0,
+            total_rounds: 3,
+            pairs: HashMap::new(),
+            pending_answers: Vec::new(),
+            guess_queue: VecDeque::new(),
+            current_answer: None,
+            rng,
+            autosave_path: None,
+            transcript: Vec::new(),
+            round_start_scores: HashMap::new(),
+            scoring: ScoringRules::default(),
+            decoy_mode: false,
+            current_question_guesses: Vec::new(),
+        }
+    }
+
+    pub fn frontend_mut(&mut self) -> &mut F {
+        &mut self.frontend
+    }
+
+    /// If set, the game is saved to `path` after every question, answer,
+    /// and guess (not just at round boundaries), so an interrupted session
+    /// loses at most the input in flight when it's resumed with `load`.
+    pub fn set_autosave_path(&mut self, path: Option<String>) {
+        self.autosave_path = path;
+    }
+
+    pub fn set_scoring_rules(&mut self, rules: ScoringRules) {
+        self.scoring = rules;
+    }
+
+    pub fn scoring_rules(&self) -> ScoringRules {
+        self.scoring
+    }
+
+    /// Turns bluff mode on or off: when on, every non-answering player also
+    /// writes a decoy answer, and guessers must pick the genuine one out of
+    /// the real answer and all the decoys, shuffled together.
+    pub fn set_decoy_mode(&mut self, enabled: bool) {
+        self.decoy_mode = enabled;
+    }
+
+    pub fn write_transcript(&self, path: &str) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.transcript)
+            .expect("transcript entries are always serializable");
+        fs::write(path, json)
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let snapshot = GameSnapshot {
+            next_player_id: self.next_player_id,
+            players: &self.players,
+            phase: self.phase,
+            round: self.round,
+            total_rounds: self.total_rounds,
+            pairs: &self.pairs,
+            pending_answers: &self.pending_answers,
+            guess_queue: &self.guess_queue,
+            current_answer: &self.current_answer,
+            scoring: self.scoring,
+            decoy_mode: self.decoy_mode,
+            round_start_scores: &self.round_start_scores,
+        };
+        let json = serde_json::to_string_pretty(&snapshot)
+            .expect("game state is always serializable");
+        fs::write(path, json)
+    }
+
+    /// Restores a game saved with `save`, reattaching `frontend`. Loaded
+    /// players never carry a `PlayerController` (see `Player::controller`),
+    /// so this is meant for resuming human games, not bot simulations.
+    pub fn load(path: &str, frontend: F) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        let snapshot: OwnedGameSnapshot =
+            serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Self {
+            next_player_id: snapshot.next_player_id,
+            players: snapshot.players,
+            frontend,
+            phase: snapshot.phase,
+            round: snapshot.round,
+            total_rounds: snapshot.total_rounds,
+            pairs: snapshot.pairs,
+            pending_answers: snapshot.pending_answers,
+            guess_queue: snapshot.guess_queue,
+            current_answer: snapshot.current_answer,
+            rng: StdRng::from_entropy(),
+            autosave_path: None,
+            transcript: Vec::new(),
+            round_start_scores: snapshot.round_start_scores,
+            scoring: snapshot.scoring,
+            decoy_mode: snapshot.decoy_mode,
+            current_question_guesses: Vec::new(),
+        })
+    }
+
+    /// All player IDs in ascending order, so that iteration order (and
+    /// therefore what a seeded RNG is applied to) doesn't depend on
+    /// `HashMap` ordering.
+    pub fn player_ids(&self) -> Vec<PlayerID> {
+        let mut ids: Vec<PlayerID> = self.players.keys().cloned().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    pub fn add_new_player(&mut self, nickname: String) -> PlayerID {
+        self.insert_player(nickname, None)
+    }
+
+    pub fn add_bot_player(&mut self, nickname: String, controller: Box<dyn PlayerController>) -> PlayerID {
+        self.insert_player(nickname, Some(controller))
+    }
+
+    fn insert_player(&mut self, nickname: String, controller: Option<Box<dyn PlayerController>>) -> PlayerID {
+        let id = self.next_player_id;
+        self.next_player_id += 1;
+        self.players.insert(id, Player::new(id, nickname, controller));
+        id
+    }
+
+    /// Drives a whole game start-to-finish against `self.frontend`,
+    /// prompting players one at a time. Both `TerminalFrontend` and the
+    /// network `ServerFrontend` are driven this way; callers wanting finer
+    /// control can call `collect_question` / `submit_answer` /
+    /// `record_guess` / `advance` directly instead.
+    pub fn start(&mut self) {
+        loop {
+            match self.advance() {
+                GamePhase::CollectingQuestions => self.run_collect_questions(),
+                GamePhase::CollectingAnswers => self.run_collect_answers(),
+                GamePhase::Guessing => self.run_guessing(),
+                GamePhase::RoundComplete => {}
+                GamePhase::Finished => break,
+                GamePhase::Lobby => unreachable!("advance() never returns to Lobby"),
+            }
+        }
+        self.show_final_scores();
+    }
+
+    /// Best-effort save to `autosave_path`, if one is set. A failed autosave
+    /// shouldn't crash an otherwise-fine game, so errors are just printed.
+    fn autosave(&self) {
+        if let Some(path) = &self.autosave_path {
+            if let Err(e) = self.save(path) {
+                eprintln!("warning: autosave to {} failed: {}", path, e);
+            }
+        }
+    }
+
+    fn run_collect_questions(&mut self) {
+        let ctx = QuestionCtx { round: self.round };
+        for author in self.player_ids() {
+            let prompt = match self.players.get_mut(&author).unwrap().controller.as_mut() {
+                Some(bot) => bot.write_question(&ctx),
+                None => self.frontend.prompt_question(&self.players[&author]),
+            };
+            self.collect_question(author, prompt);
+        }
+    }
+
+    fn run_collect_answers(&mut self) {
+        for player in self.player_ids() {
+            let question = self.players[&player]
+                .question_pending_answer
+                .as_ref()
+                .expect("every player was paired with an author in collect_question")
+                .prompt
+                .clone();
+            let answer = match self.players.get_mut(&player).unwrap().controller.as_mut() {
+                Some(bot) => bot.answer(&question),
+                None => self.frontend.prompt_answer(&self.players[&player], &question),
+            };
+            self.submit_answer(player, answer);
+        }
+        if self.decoy_mode {
+            self.run_collect_decoys();
+        }
+    }
+
+    /// For each answer collected this round, asks every player except the
+    /// one who actually answered it to also write a fake answer to the
+    /// same question.
+    fn run_collect_decoys(&mut self) {
+        for idx in 0..self.pending_answers.len() {
+            let answered_by = self.pending_answers[idx].answered_by;
+            let question = self.pending_answers[idx].question.prompt.clone();
+            for author in self.player_ids().into_iter().filter(|&id| id != answered_by) {
+                let decoy = match self.players.get_mut(&author).unwrap().controller.as_mut() {
+                    Some(bot) => bot.answer(&question),
+                    None => self.frontend.prompt_answer(&self.players[&author], &question),
+                };
+                self.transcript.push(TranscriptEntry::Decoy {
+                    round: self.round,
+                    author,
+                    answer: decoy.clone(),
+                });
+                self.pending_answers[idx].decoys.push((author, decoy));
+                self.autosave();
+            }
+        }
+    }
+
+    fn run_guessing(&mut self) {
+        loop {
+            let current = self.current_answer.as_ref().unwrap();
+            let answerer = current.answered_by;
+            let question = current.question.prompt.clone();
+            let answer = current.answer.clone();
+            let mut candidate_answers: Vec<(PlayerID, String)> = Vec::new();
+            if self.decoy_mode {
+                candidate_answers.push((answerer, answer.clone()));
+                candidate_answers.extend(current.decoys.iter().cloned());
+                candidate_answers.shuffle(&mut self.rng);
+            }
+            let mut guessers: Vec<PlayerID> =
+                self.player_ids().into_iter().filter(|&id| id != answerer).collect();
+            guessers.shuffle(&mut self.rng);
+            for guesser in guessers {
+                // In decoy mode every guesser also authored one of the
+                // candidates (their own decoy, or the real answer), so that
+                // candidate must be dropped here, not just from `guessers`.
+                let candidate_ids = self.valid_guess_candidates(guesser);
+                let guessed = match self.players.get_mut(&guesser).unwrap().controller.as_mut() {
+                    Some(bot) => bot.guess(self.current_answer.as_ref().unwrap(), &candidate_ids),
+                    None if self.decoy_mode => {
+                        let candidates: Vec<GuessCandidate> = candidate_answers
+                            .iter()
+                            .filter(|(id, _)| *id != guesser)
+                            .map(|(id, text)| GuessCandidate { player: &self.players[id], answer: text })
+                            .collect();
+                        self.frontend.prompt_bluff_guess(&self.players[&guesser], &question, &candidates)
+                    }
+                    None => {
+                        let candidates: Vec<&Player> = self
+                            .players
+                            .values()
+                            .filter(|p| p.id != guesser)
+                            .collect();
+                        self.frontend.prompt_guess(
+                            &self.players[&guesser],
+                            &question,
+                            &answer,
+                            &candidates,
+                        )
+                    }
+                };
+                self.record_guess(guesser, guessed);
+            }
+            if self.advance() != GamePhase::Guessing {
+                break;
+            }
+        }
+    }
+
+    /// The set of player IDs `guesser` may legitimately pick from for the
+    /// question currently being guessed: everyone who could have written
+    /// the shown answer, excluding `guesser` themself (who, in decoy mode,
+    /// would otherwise always recognize their own authored candidate).
+    fn valid_guess_candidates(&self, guesser: PlayerID) -> Vec<PlayerID> {
+        let current = self
+            .current_answer
+            .as_ref()
+            .expect("valid_guess_candidates called while in the Guessing phase");
+        if self.decoy_mode {
+            let mut ids = vec![current.answered_by];
+            ids.extend(current.decoys.iter().map(|(id, _)| *id));
+            ids.retain(|&id| id != guesser);
+            ids
+        } else {
+            self.player_ids().into_iter().filter(|&id| id != guesser).collect()
+        }
+    }
+
+    /// Moves the game to its next phase, performing whatever bookkeeping
+    /// that transition requires, and returns the phase landed on.
+    pub fn advance(&mut self) -> GamePhase {
+        self.phase = match self.phase {
+            GamePhase::Lobby | GamePhase::RoundComplete => {
+                if self.round >= self.total_rounds {
+                    GamePhase::Finished
+                } else {
+                    self.round += 1;
+                    self.pairs = self.generate_player_pairs();
+                    self.round_start_scores =
+                        self.players.iter().map(|(&id, p)| (id, p.score)).collect();
+                    self.frontend.show(&GameEvent::RoundStarted {
+                        round: self.round,
+                        total_rounds: self.total_rounds,
+                    });
+                    GamePhase::CollectingQuestions
+                }
+            }
+            GamePhase::CollectingQuestions => {
+                self.frontend.show(&GameEvent::QuestionsCollected);
+                GamePhase::CollectingAnswers
+            }
+            GamePhase::CollectingAnswers => {
+                self.frontend.show(&GameEvent::AnswersCollected);
+                self.guess_queue = self.pending_answers.drain(..).collect();
+                self.begin_next_guess()
+            }
+            GamePhase::Guessing => self.begin_next_guess(),
+            GamePhase::Finished => GamePhase::Finished,
+        };
+        if self.phase == GamePhase::RoundComplete {
+            self.autosave();
+        }
+        self.phase
+    }
+
+    fn begin_next_guess(&mut self) -> GamePhase {
+        if let Some(finished) = self.current_answer.take() {
+            self.award_lone_asker_bonus(&finished);
+            self.frontend.show(&GameEvent::AnswerResults {
+                answerer: finished.answered_by,
+                answerer_nickname: self.players[&finished.answered_by].nickname.clone(),
+                scores: self.scoreboard(),
+            });
+            self.current_question_guesses.clear();
+        }
+        match self.guess_queue.pop_front() {
+            Some(answered) => {
+                self.current_answer = Some(answered);
+                GamePhase::Guessing
+            }
+            None => {
+                let deltas = self
+                    .players
+                    .values()
+                    .map(|p| {
+                        let start = *self.round_start_scores.get(&p.id).unwrap_or(&0);
+                        (p.nickname.clone(), p.score as i64 - start as i64)
+                    })
+                    .collect();
+                self.transcript.push(TranscriptEntry::RoundScores {
+                    round: self.round,
+                    deltas,
+                });
+                self.frontend.show(&GameEvent::RoundComplete);
+                GamePhase::RoundComplete
+            }
+        }
+    }
+
+    /// Awards `finished`'s asker a bonus if they were the only one of this
+    /// question's guessers to correctly identify the answerer.
+    fn award_lone_asker_bonus(&mut self, finished: &AnsweredQuestion) {
+        if self.scoring.lone_asker_bonus == 0 {
+            return;
+        }
+        let author = finished.question.author;
+        let mut correct_guessers = self
+            .current_question_guesses
+            .iter()
+            .filter(|(_, outcome)| *outcome == GuessOutcome::Correct)
+            .map(|(guesser, _)| *guesser);
+        if correct_guessers.next() == Some(author) && correct_guessers.next().is_none() {
+            self.players.get_mut(&author).unwrap().score += self.scoring.lone_asker_bonus;
+        }
+    }
+
+    fn scoreboard(&self) -> Vec<(String, u32)> {
+        self.players.values().map(|p| (p.nickname.clone(), p.score)).collect()
+    }
+
+    /// Stores `prompt` as the question `author` wrote, to be answered by
+    /// whoever this round's pairing assigned them.
+    pub fn collect_question(&mut self, author: PlayerID, prompt: String) {
+        let responder = *self
+            .pairs
+            .get(&author)
+            .expect("pairs are generated before CollectingQuestions begins");
+        self.transcript.push(TranscriptEntry::Question {
+            round: self.round,
+            author,
+            prompt: prompt.clone(),
+        });
+        let question = Question::new(author, prompt);
+        self.players.get_mut(&responder).unwrap().question_pending_answer = Some(question);
+        self.autosave();
+    }
+
+    /// Records `player`'s answer to the question they were assigned.
+    pub fn submit_answer(&mut self, player: PlayerID, answer: String) {
+        let question = self.players.get_mut(&player).unwrap()
+            .question_pending_answer
+            .take()
+            .expect("player was assigned a question to answer this round");
+        self.transcript.push(TranscriptEntry::Answer {
+            round: self.round,
+            player,
+            answer: answer.clone(),
+        });
+        self.pending_answers.push(question.respond(player, answer));
+        self.autosave();
+    }
+
+    /// Records `guesser`'s guess of who wrote the answer currently being
+    /// guessed, awards points, and returns whether it was right.
+    ///
+    /// `guessed` ultimately comes from the frontend (e.g. over the network,
+    /// straight from a client), so it isn't trusted: anything outside
+    /// `valid_guess_candidates` is scored as a plain incorrect guess rather
+    /// than indexed into `self.players`.
+    pub fn record_guess(&mut self, guesser: PlayerID, guessed: PlayerID) -> GuessOutcome {
+        let answered_by = self
+            .current_answer
+            .as_ref()
+            .expect("record_guess called while in the Guessing phase")
+            .answered_by;
+        let is_valid_candidate = self.valid_guess_candidates(guesser).contains(&guessed);
+        let outcome = if is_valid_candidate && guessed == answered_by {
+            GuessOutcome::Correct
+        } else {
+            GuessOutcome::Incorrect
+        };
+        let points = self.scoring.points_for_round(self.round);
+        if outcome == GuessOutcome::Correct {
+            self.players.get_mut(&guesser).unwrap().score += points;
+        } else if self.decoy_mode && is_valid_candidate {
+            // The only other candidates offered are decoy authors, so
+            // `guessed` fooled `guesser` into picking their fake.
+            self.players.get_mut(&guessed).unwrap().score += points;
+        }
+        self.current_question_guesses.push((guesser, outcome));
+        self.transcript.push(TranscriptEntry::Guess {
+            round: self.round,
+            guesser,
+            guessed,
+            correct: outcome == GuessOutcome::Correct,
+        });
+        self.frontend.show(&GameEvent::GuessResult {
+            guesser_nickname: self.players[&guesser].nickname.clone(),
+            correct: outcome == GuessOutcome::Correct,
+            points: if outcome == GuessOutcome::Correct { points } else { 0 },
+        });
+        self.autosave();
+        outcome
+    }
+
+    fn show_final_scores(&mut self) {
+        let mut players: Vec<_> = self.players.values().collect();
+        players.sort_by_key(|p| std::cmp::Reverse(p.score));
+        let scores = players
+            .iter()
+            .map(|p| (p.nickname.clone(), p.score))
+            .collect();
+        self.frontend.show(&GameEvent::FinalScores(scores));
+    }
+
+    fn generate_player_pairs(&mut self) -> HashMap<PlayerID, PlayerID> {
+        let mut order = self.player_ids();
+        order.shuffle(&mut self.rng);
+        let mut pairs = HashMap::with_capacity(order.len());
+        let mut asker = *order.last().unwrap();
+        for responder in order {
+            pairs.insert(asker, responder);
+            asker = responder;
+        }
+        pairs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::RandomBot;
+    use crate::frontend::headless::HeadlessFrontend;
+
+    fn bot_game(seed: u64, num_bots: u8) -> Game<HeadlessFrontend> {
+        let mut game = Game::new_with_seed(HeadlessFrontend, seed);
+        for i in 0..num_bots {
+            game.add_bot_player(format!("bot{}", i), Box::new(RandomBot::new(seed.wrapping_add(i as u64))));
+        }
+        game
+    }
+
+    fn sorted_scores<F: Frontend>(game: &Game<F>) -> Vec<(String, u32)> {
+        let mut scores: Vec<_> = game.players.values().map(|p| (p.nickname.clone(), p.score)).collect();
+        scores.sort();
+        scores
+    }
+
+    #[test]
+    fn seeded_bot_game_is_reproducible() {
+        let mut a = bot_game(42, 3);
+        a.start();
+        let mut b = bot_game(42, 3);
+        b.start();
+        assert_eq!(sorted_scores(&a), sorted_scores(&b));
+    }
+
+    #[test]
+    fn save_load_round_trip_preserves_config_and_progress() {
+        let path = std::env::temp_dir().join("interrogate-test-save-load-round-trip.json");
+        let path = path.to_str().unwrap();
+
+        let mut game = bot_game(7, 2);
+        game.set_scoring_rules(ScoringRules {
+            correct_guess_points: 3,
+            lone_asker_bonus: 2,
+            round_escalation: 1,
+        });
+        game.set_decoy_mode(true);
+        game.start();
+        game.save(path).unwrap();
+
+        let loaded = Game::load(path, HeadlessFrontend).unwrap();
+        let _ = fs::remove_file(path);
+
+        assert_eq!(loaded.phase, GamePhase::Finished);
+        assert_eq!(loaded.round, game.round);
+        assert_eq!(loaded.scoring.correct_guess_points, 3);
+        assert_eq!(loaded.scoring.lone_asker_bonus, 2);
+        assert_eq!(loaded.scoring.round_escalation, 1);
+        assert!(loaded.decoy_mode);
+        assert_eq!(sorted_scores(&loaded), sorted_scores(&game));
+    }
+
+    #[test]
+    fn decoy_mode_excludes_guessers_own_candidate_and_rejects_invalid_guesses() {
+        let mut game = Game::new_with_seed(HeadlessFrontend, 1);
+        let a = game.add_new_player("a".to_string());
+        let b = game.add_new_player("b".to_string());
+        let c = game.add_new_player("c".to_string());
+        game.set_decoy_mode(true);
+
+        let mut answered = Question::new(a, "q".to_string()).respond(b, "real".to_string());
+        answered.decoys.push((a, "fake-a".to_string()));
+        answered.decoys.push((c, "fake-c".to_string()));
+        game.phase = GamePhase::Guessing;
+        game.current_answer = Some(answered);
+
+        // Neither the real answerer nor either decoy author is offered
+        // their own candidate.
+        for guesser in [a, b, c] {
+            assert!(!game.valid_guess_candidates(guesser).contains(&guesser));
+        }
+        let candidates_for_c = game.valid_guess_candidates(c);
+        assert!(candidates_for_c.contains(&a));
+        assert!(candidates_for_c.contains(&b));
+
+        // An id outside the offered candidates (e.g. a forged client
+        // message) is scored as a plain incorrect guess, not credited or
+        // used to index `players` directly.
+        let bogus: PlayerID = 255;
+        let before = game.players[&a].score;
+        let outcome = game.record_guess(c, bogus);
+        assert_eq!(outcome, GuessOutcome::Incorrect);
+        assert_eq!(game.players[&a].score, before);
+    }
+}
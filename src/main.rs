@@ -1,265 +1,122 @@
-use rand::{seq::SliceRandom, thread_rng};
-use std::collections::HashMap;
-use std::io::{self, Write};
+mod controller;
+mod frontend;
+mod game;
+mod net;
 
-macro_rules! flushed_print {
-    ($($arg:tt)*) => {
-        print!(
-            "{}",
-            format_args!($($arg)*),
-        );
-        io::stdout().flush().unwrap();
-    }
-}
-
-fn clear_screen() {
-    flushed_print!("\x1B[2J\x1B[1;1H");
-}
-
-fn wait_for_enter() {
-    read_line();
-}
+use controller::RandomBot;
+use frontend::headless::HeadlessFrontend;
+use frontend::terminal::TerminalFrontend;
+use game::{Game, ScoringRules};
+use std::io;
 
 fn read_line() -> String {
     let mut buf = String::new();
     io::stdin().read_line(&mut buf).unwrap();
-    // remove newline
     buf.pop();
     buf
 }
 
-type PlayerID = u8;
-
-#[derive(Debug)]
-struct Player {
-    id: PlayerID,
-    nickname: String,
-    score: u32,
-    question_pending_answer: Option<Question>,
+/// Returns the value following `flag` in `args`, if present.
+fn find_flag_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter().position(|a| a == flag).and_then(|i| args.get(i + 1)).cloned()
 }
 
-impl Player {
-    fn new(id: PlayerID, nickname: String) -> Self {
-        Self {
-            id,
-            nickname,
-            score: 0,
-            question_pending_answer: None,
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Question {
-    author: PlayerID,
-    prompt: String,
+fn has_flag(args: &[String], flag: &str) -> bool {
+    args.iter().any(|a| a == flag)
 }
 
-impl Question {
-    fn new(author: PlayerID, prompt: String) -> Self {
-        Self { author, prompt }
+/// Applies any of `--correct-guess-points`, `--lone-asker-bonus`, and
+/// `--round-escalation` present in `args` on top of `base`, leaving fields
+/// whose flag wasn't given untouched. `base` is `ScoringRules::default()`
+/// for a fresh game, or a resumed game's own loaded rules -- so a `--load`
+/// invocation without these flags doesn't silently reset them.
+fn apply_scoring_overrides(base: ScoringRules, args: &[String]) -> ScoringRules {
+    ScoringRules {
+        correct_guess_points: find_flag_value(args, "--correct-guess-points")
+            .map(|v| v.parse().expect("--correct-guess-points must be a number"))
+            .unwrap_or(base.correct_guess_points),
+        lone_asker_bonus: find_flag_value(args, "--lone-asker-bonus")
+            .map(|v| v.parse().expect("--lone-asker-bonus must be a number"))
+            .unwrap_or(base.lone_asker_bonus),
+        round_escalation: find_flag_value(args, "--round-escalation")
+            .map(|v| v.parse().expect("--round-escalation must be a number"))
+            .unwrap_or(base.round_escalation),
     }
-
-    fn respond(self, answered_by: PlayerID, answer: String) -> AnsweredQuestion {
-        AnsweredQuestion {
-            question: self,
-            answered_by,
-            answer
-        }
-    }
-}
-
-#[derive(Debug)]
-struct AnsweredQuestion {
-    question: Question,
-    answered_by: PlayerID,
-    answer: String,
 }
 
-#[derive(Debug)]
-struct Game {
-    next_player_id: PlayerID,
-    players: HashMap<PlayerID, Player>,
-}
-
-impl Game {
-    fn new() -> Self {
-        Self {
-            next_player_id: 0,
-            players: HashMap::new(),
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    match args.get(1).map(String::as_str) {
+        Some("--serve") => {
+            let port: u16 = args.get(2).expect("usage: --serve <port> <num_players>")
+                .parse().expect("port must be a number");
+            let num_players: usize = args.get(3)
+                .expect("usage: --serve <port> <num_players>")
+                .parse()
+                .expect("num_players must be a number");
+            let scoring = apply_scoring_overrides(ScoringRules::default(), &args);
+            net::run_server(port, num_players, scoring, has_flag(&args, "--decoys")).expect("server error");
         }
-    }
-
-    fn player_ids(&self) -> Vec<PlayerID> {
-        self.players.keys().cloned().collect()
-    }
-
-    fn add_new_player(&mut self, nickname: String) {
-        let id = self.next_player_id;
-        self.next_player_id += 1;
-        self.players.insert(id, Player::new(id, nickname));
-    }
-
-    fn start(&mut self) {
-        for round in 1..=3 {
-            clear_screen();
-            flushed_print!("=> Press <ENTER> to start round {}", round);
-            wait_for_enter();
-            self.pend_questions();
-            flushed_print!("=> Press <ENTER> to start answering");
-            wait_for_enter();
-            let answers = self.input_answers();
-            clear_screen();
-            self.do_guesses(&answers);
+        Some("--connect") => {
+            let addr = args.get(2).expect("usage: --connect <addr> <nickname>");
+            let nickname = args.get(3).expect("usage: --connect <addr> <nickname>").clone();
+            net::run_client(addr, nickname).expect("client error");
         }
-        clear_screen();
-        self.show_final_scores();
-    }
-
-    fn show_final_scores(&self) {
-        let mut players: Vec<_> = self.players.values().collect();
-        players.sort_by(|a, b| b.score.cmp(&a.score));
-        let max_nickname_len = players.iter().max_by(|a, b| a.nickname.len().cmp(&b.nickname.len())).unwrap().nickname.len();
-        println!("=> Final scores:");
-        for player in &players {
-            let padded_nickname = format!("{}{}", player.nickname, " ".repeat(max_nickname_len - player.nickname.len()));
-            println!("{}: {} points", padded_nickname, player.score);
+        Some("--simulate") => {
+            let num_bots: u8 = args.get(2).expect("usage: --simulate <num_bots> <seed>")
+                .parse().expect("num_bots must be a number");
+            let seed: u64 = args.get(3).expect("usage: --simulate <num_bots> <seed>")
+                .parse().expect("seed must be a number");
+            let scoring = apply_scoring_overrides(ScoringRules::default(), &args);
+            run_simulation(num_bots, seed, has_flag(&args, "--decoys"), scoring);
         }
+        _ => run_local(&args),
     }
+}
 
-    fn do_guesses(&mut self, answers: &[AnsweredQuestion]) {
-        for answered_q in answers {
-            // tuple is (guesser, guess)
-            let mut guesses: Vec<(PlayerID, PlayerID)> = Vec::new();
-            let mut ps = self.players.values().collect::<Vec<_>>();
-            clear_screen();
-            ps.shuffle(&mut thread_rng());
-            for player in ps {
-                flushed_print!("=> {}, press enter to start guessing", player.nickname);
-                wait_for_enter();
-                clear_screen();
-                if player.id == answered_q.answered_by {
-                    println!("=> {}, your question is being guessed this round. Type a random number and press enter, so that people don't realize this is your question. (your answer will be ignored)", player.nickname);
-                    wait_for_enter();
+/// Default autosave location for local games, so a crash or closed
+/// terminal can be recovered with `--load`.
+const AUTOSAVE_PATH: &str = "interrogate-autosave.json";
+
+fn run_local(args: &[String]) {
+    let mut game = match find_flag_value(args, "--load") {
+        Some(path) => Game::load(&path, TerminalFrontend::new()).expect("failed to load save file"),
+        None => {
+            let mut game = Game::new(TerminalFrontend::new());
+            loop {
+                print!("Who's playing? Enter your name then press enter (or press enter if there are no more players to add): ");
+                io::Write::flush(&mut io::stdout()).unwrap();
+                let line = read_line();
+                if line.is_empty() {
+                    break;
                 } else {
-                    println!("=> question:\n\t{}", answered_q.question.prompt);
-                    println!("=> response:\n\t{}", answered_q.answer);
-                    println!();
-                    println!("=> ids:");
-                    let mut sorted_players: Vec<_> = self.players.values().collect();
-                    sorted_players.sort_by(|a, b| a.id.cmp(&b.id));
-                    for player in sorted_players {
-                        println!("{:2}: {}", player.id, player.nickname);
-                    }
-                    println!("=> {}, who do you think wrote this answer? Enter an ID from above.", player.nickname);
-                    let guess: PlayerID;
-                    loop {
-                        flushed_print!("{}'s guess: ", player.nickname);
-                        let input = read_line();
-                        match input.parse() {
-                            Ok(id) if self.players.contains_key(&id) => {
-                                if id != player.id {
-                                    guess = id;
-                                    break;
-                                } else {
-                                    println!("=> You can't guess yourself!");
-                                }
-                            },
-                            _ => {
-                                println!("=> You need to enter an ID from the list.");
-                            },
-                        }
-                    }
-                    guesses.push((player.id, guess));
+                    game.add_new_player(line);
                 }
-                clear_screen();
             }
-            flushed_print!("=> Guessing done. Press <ENTER> to see the results.");
-            wait_for_enter();
-            let answerer = self.players.get(&answered_q.answered_by).unwrap();
-            println!("=> **{}** was the one who answered the question.", answerer.nickname);
-            for (guesser_id, guessed_id) in guesses {
-                let guesser = self.players.get_mut(&guesser_id).unwrap();
-                if guessed_id == answered_q.answered_by {
-                    let points = 1;
-                    println!("=> {} was CORRECT. +{} points.", guesser.nickname, points);
-                    println!("TODO: bonus if the asker is the only one who guessed correctly");
-                    guesser.score += points;
-                } else {
-                    println!("=> {} was INCORRECT.", guesser.nickname);
-                }
-            }
-            flushed_print!("=> Press <ENTER> continue.");
-            wait_for_enter();
+            game
         }
+    };
+    game.set_autosave_path(Some(AUTOSAVE_PATH.to_string()));
+    game.set_scoring_rules(apply_scoring_overrides(game.scoring_rules(), args));
+    if has_flag(args, "--decoys") {
+        game.set_decoy_mode(true);
     }
-
-    fn input_answers(&mut self) -> Vec<AnsweredQuestion> {
-        let mut answers = Vec::new();
-        for p in self.player_ids() {
-            self.summon_player(p);
-            let pending_q = self.players.get_mut(&p).unwrap()
-                .question_pending_answer.take().unwrap();
-            println!("=> Answer this question:\n\t{}", pending_q.prompt);
-            flushed_print!("Answer: ");
-            let response = read_line();
-            answers.push(pending_q.respond(p, response));
-        }
-        clear_screen();
-        answers
-    }
-
-    fn summon_player(&self, p: PlayerID) {
-        clear_screen();
-        let player = self.players.get(&p).unwrap();
-        flushed_print!("=> {}, press <ENTER>", player.nickname);
-        wait_for_enter();
-    }
-
-    fn pend_questions(&mut self) {
-        let pairs: HashMap<_, _> = self.generate_player_pairs().into_iter().collect();
-        for p in self.player_ids() {
-            self.summon_player(p);
-
-            let question = self.input_question(p);
-            let responder = pairs.get(&p).unwrap();
-            self.players.get_mut(&responder).unwrap()
-                .question_pending_answer = Some(question);
-
-            clear_screen();
-        }
-    }
-
-    fn input_question(&self, author: PlayerID) -> Question {
-        flushed_print!("Enter a question: ");
-        let prompt = read_line();
-
-        Question::new(author, prompt)
-    }
-
-    fn generate_player_pairs(&self) -> Vec<(PlayerID, PlayerID)> {
-        let mut order: Vec<_> = self.players.keys().cloned().collect();
-        order.shuffle(&mut thread_rng());
-        let mut pairs = Vec::with_capacity(order.len());
-        let mut asker = *order.last().unwrap();
-        for responder in order {
-            pairs.push((asker, responder));
-            asker = responder;
-        }
-        pairs
+    game.start();
+    if let Some(path) = find_flag_value(args, "--transcript") {
+        game.write_transcript(&path).expect("failed to write transcript");
     }
 }
 
-fn main() {
-    let mut game = Game::new();
-    loop {
-        flushed_print!("Who's playing? Enter your name then press enter (or press enter if there are no more players to add): ");
-        let line = read_line();
-        if line == "" {
-            break;
-        } else {
-            game.add_new_player(line);
-        }
-    }
+/// Runs a full headless game of `num_bots` `RandomBot`s, seeded so the
+/// whole game (pairings, questions, answers, guesses) is reproducible.
+/// Useful for testing and balancing the scoring rules without a human in
+/// the loop.
+fn run_simulation(num_bots: u8, seed: u64, decoy_mode: bool, scoring: ScoringRules) {
+    let mut game = Game::new_with_seed(HeadlessFrontend, seed);
+    for i in 0..num_bots {
+        game.add_bot_player(format!("bot{}", i), Box::new(RandomBot::new(seed.wrapping_add(i as u64))));
+    }
+    game.set_decoy_mode(decoy_mode);
+    game.set_scoring_rules(scoring);
     game.start();
 }
@@ -0,0 +1,113 @@
+use super::protocol::{ClientHello, ClientMessage, ServerMessage};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+
+fn send(stream: &mut TcpStream, msg: &ClientMessage) {
+    let line = serde_json::to_string(msg).unwrap();
+    writeln!(stream, "{}", line).unwrap();
+}
+
+fn read_line() -> String {
+    let mut buf = String::new();
+    io::stdin().read_line(&mut buf).unwrap();
+    buf.pop();
+    buf
+}
+
+/// Connects to `addr`, joins as `nickname`, and plays the game by
+/// prompting this terminal whenever the server asks.
+pub fn run_client(addr: &str, nickname: String) -> io::Result<()> {
+    let stream = TcpStream::connect(addr)?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+
+    let line = serde_json::to_string(&ClientHello::Join { nickname }).unwrap();
+    writeln!(writer, "{}", line)?;
+
+    println!("=> Connected. Waiting for the rest of the players...");
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            println!("=> Server closed the connection.");
+            return Ok(());
+        }
+        let msg: ServerMessage = match serde_json::from_str(&line) {
+            Ok(msg) => msg,
+            Err(e) => {
+                println!("=> received a malformed message from the server: {}", e);
+                return Ok(());
+            }
+        };
+        match msg {
+            ServerMessage::RoundStarted { round, total_rounds } => {
+                println!("=> Round {}/{} starting.", round, total_rounds);
+            }
+            ServerMessage::PromptQuestion => {
+                print!("Enter a question for someone else to answer: ");
+                io::stdout().flush()?;
+                send(&mut writer, &ClientMessage::Question { text: read_line() });
+            }
+            ServerMessage::PromptAnswer { question } => {
+                println!("=> Answer this question:\n\t{}", question);
+                print!("Answer: ");
+                io::stdout().flush()?;
+                send(&mut writer, &ClientMessage::Answer { text: read_line() });
+            }
+            ServerMessage::PromptGuess { question, answer, candidates } => {
+                println!("=> question:\n\t{}", question);
+                println!("=> response:\n\t{}", answer);
+                println!("=> candidates:");
+                for c in &candidates {
+                    println!("{:2}: {}", c.id, c.nickname);
+                }
+                let guess = loop {
+                    print!("Who wrote this? Enter an ID: ");
+                    io::stdout().flush()?;
+                    match read_line().parse() {
+                        Ok(id) if candidates.iter().any(|c| c.id == id) => break id,
+                        _ => println!("=> You need to enter an ID from the list."),
+                    }
+                };
+                send(&mut writer, &ClientMessage::Guess { id: guess });
+            }
+            ServerMessage::PromptBluffGuess { question, candidates } => {
+                println!("=> question:\n\t{}", question);
+                println!("=> candidate answers:");
+                for c in &candidates {
+                    println!("{:2}: {}", c.id, c.answer);
+                }
+                let guess = loop {
+                    print!("Which is the genuine answer? Enter an ID: ");
+                    io::stdout().flush()?;
+                    match read_line().parse() {
+                        Ok(id) if candidates.iter().any(|c| c.id == id) => break id,
+                        _ => println!("=> You need to enter an ID from the list."),
+                    }
+                };
+                send(&mut writer, &ClientMessage::Guess { id: guess });
+            }
+            ServerMessage::Results { answerer_nickname, scores, .. } => {
+                println!("=> {} wrote that answer.", answerer_nickname);
+                print_scores(&scores.into_iter().collect::<Vec<_>>());
+            }
+            ServerMessage::RoundComplete => {
+                println!("=> Round complete.");
+            }
+            ServerMessage::FinalScores { scores } => {
+                println!("=> Final scores:");
+                print_scores(&scores);
+                return Ok(());
+            }
+            ServerMessage::GameAborted { reason } => {
+                println!("=> Game aborted: {}", reason);
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn print_scores(scores: &[(String, u32)]) {
+    for (nickname, score) in scores {
+        println!("{}: {} points", nickname, score);
+    }
+}
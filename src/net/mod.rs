@@ -0,0 +1,11 @@
+//! Network multiplayer: each player connects from their own terminal over
+//! TCP instead of passing one terminal around, so private prompts (writing
+//! a question, answering one, guessing an author) stay private. Messages
+//! are newline-delimited JSON, one object per line.
+
+pub mod client;
+pub mod protocol;
+pub mod server;
+
+pub use client::run_client;
+pub use server::run_server;
@@ -0,0 +1,70 @@
+//! The wire format spoken between server and clients: one JSON object per
+//! line, tagged by `"type"`.
+
+use crate::game::PlayerID;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandidateInfo {
+    pub id: PlayerID,
+    pub nickname: String,
+}
+
+/// A bluff-mode guess candidate: a player and the (real or fake) answer
+/// text attributed to them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BluffCandidateInfo {
+    pub id: PlayerID,
+    pub nickname: String,
+    pub answer: String,
+}
+
+/// Sent by a client when it first connects, before the game starts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientHello {
+    Join { nickname: String },
+}
+
+/// Sent by a client in reply to whichever `ServerMessage` prompt it was
+/// last sent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    Question { text: String },
+    Answer { text: String },
+    Guess { id: PlayerID },
+}
+
+/// Sent by the server. A client only ever receives a prompt meant for it,
+/// or a `show`-style event that's safe for everyone to see.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    RoundStarted { round: u32, total_rounds: u32 },
+    PromptQuestion,
+    PromptAnswer { question: String },
+    PromptGuess {
+        question: String,
+        answer: String,
+        candidates: Vec<CandidateInfo>,
+    },
+    PromptBluffGuess {
+        question: String,
+        candidates: Vec<BluffCandidateInfo>,
+    },
+    /// `scores` is keyed by nickname, since JSON object keys must be
+    /// strings.
+    Results {
+        answerer: PlayerID,
+        answerer_nickname: String,
+        scores: HashMap<String, u32>,
+    },
+    RoundComplete,
+    FinalScores { scores: Vec<(String, u32)> },
+    /// Sent to every remaining client when the game can't continue because
+    /// one player's connection dropped or sent something the server
+    /// couldn't make sense of.
+    GameAborted { reason: String },
+}
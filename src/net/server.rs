@@ -0,0 +1,220 @@
+use super::protocol::{BluffCandidateInfo, CandidateInfo, ClientHello, ClientMessage, ServerMessage};
+use crate::frontend::{Frontend, GameEvent, GuessCandidate};
+use crate::game::{Game, Player, PlayerID, ScoringRules};
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+
+struct Connection {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Connection {
+    fn new(stream: TcpStream) -> io::Result<Self> {
+        Ok(Self {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+        })
+    }
+
+    /// Best-effort: a client whose socket is already broken (e.g. because
+    /// it just disconnected) shouldn't make broadcasting to everyone else
+    /// fail too.
+    fn send(&mut self, msg: &ServerMessage) {
+        let line = serde_json::to_string(msg).unwrap();
+        let _ = writeln!(self.writer, "{}", line);
+    }
+
+    fn recv_hello(&mut self) -> io::Result<ClientHello> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "connection closed before joining"));
+        }
+        serde_json::from_str(&line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn recv(&mut self) -> io::Result<ClientMessage> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "connection closed"));
+        }
+        serde_json::from_str(&line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// Drives a `Game` by fanning its prompts and events out to connected
+/// sockets instead of a local terminal. Each player only ever receives the
+/// prompts meant for them; `show` events broadcast to everyone since none
+/// of them reveal anything a player shouldn't yet know.
+pub struct ServerFrontend {
+    connections: HashMap<PlayerID, Connection>,
+}
+
+impl ServerFrontend {
+    fn broadcast(&mut self, msg: &ServerMessage) {
+        for conn in self.connections.values_mut() {
+            conn.send(msg);
+        }
+    }
+
+    /// Reads `player`'s next message. A dropped connection or malformed
+    /// line ends the game for everyone (via `abort`) instead of panicking
+    /// the server process out from under every other connected player.
+    fn recv_or_abort(&mut self, player: &Player) -> ClientMessage {
+        let result = self.connections.get_mut(&player.id).unwrap().recv();
+        match result {
+            Ok(msg) => msg,
+            Err(e) => self.abort(&format!("lost connection to {}: {}", player.nickname, e)),
+        }
+    }
+
+    /// Tells every remaining client why the game is ending, then exits.
+    /// Used instead of panicking so one flaky client doesn't crash the
+    /// process with an opaque panic for everyone else.
+    fn abort(&mut self, reason: &str) -> ! {
+        eprintln!("=> game aborted: {}", reason);
+        self.broadcast(&ServerMessage::GameAborted {
+            reason: reason.to_string(),
+        });
+        std::process::exit(1);
+    }
+}
+
+impl Frontend for ServerFrontend {
+    fn prompt_question(&mut self, author: &Player) -> String {
+        self.connections.get_mut(&author.id).unwrap().send(&ServerMessage::PromptQuestion);
+        match self.recv_or_abort(author) {
+            ClientMessage::Question { text } => text,
+            other => self.abort(&format!("expected a question from {}, got {:?}", author.nickname, other)),
+        }
+    }
+
+    fn prompt_answer(&mut self, player: &Player, question: &str) -> String {
+        self.connections.get_mut(&player.id).unwrap().send(&ServerMessage::PromptAnswer {
+            question: question.to_string(),
+        });
+        match self.recv_or_abort(player) {
+            ClientMessage::Answer { text } => text,
+            other => self.abort(&format!("expected an answer from {}, got {:?}", player.nickname, other)),
+        }
+    }
+
+    fn prompt_guess(
+        &mut self,
+        guesser: &Player,
+        question: &str,
+        answer: &str,
+        candidates: &[&Player],
+    ) -> PlayerID {
+        let candidates = candidates
+            .iter()
+            .map(|p| CandidateInfo {
+                id: p.id,
+                nickname: p.nickname.clone(),
+            })
+            .collect();
+        self.connections.get_mut(&guesser.id).unwrap().send(&ServerMessage::PromptGuess {
+            question: question.to_string(),
+            answer: answer.to_string(),
+            candidates,
+        });
+        match self.recv_or_abort(guesser) {
+            ClientMessage::Guess { id } => id,
+            other => self.abort(&format!("expected a guess from {}, got {:?}", guesser.nickname, other)),
+        }
+    }
+
+    fn prompt_bluff_guess(
+        &mut self,
+        guesser: &Player,
+        question: &str,
+        candidates: &[GuessCandidate],
+    ) -> PlayerID {
+        let candidates = candidates
+            .iter()
+            .map(|c| BluffCandidateInfo {
+                id: c.player.id,
+                nickname: c.player.nickname.clone(),
+                answer: c.answer.to_string(),
+            })
+            .collect();
+        self.connections.get_mut(&guesser.id).unwrap().send(&ServerMessage::PromptBluffGuess {
+            question: question.to_string(),
+            candidates,
+        });
+        match self.recv_or_abort(guesser) {
+            ClientMessage::Guess { id } => id,
+            other => self.abort(&format!("expected a guess from {}, got {:?}", guesser.nickname, other)),
+        }
+    }
+
+    fn show(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::RoundStarted { round, total_rounds } => {
+                self.broadcast(&ServerMessage::RoundStarted {
+                    round: *round,
+                    total_rounds: *total_rounds,
+                });
+            }
+            GameEvent::RoundComplete => self.broadcast(&ServerMessage::RoundComplete),
+            GameEvent::AnswerResults { answerer, answerer_nickname, scores } => {
+                self.broadcast(&ServerMessage::Results {
+                    answerer: *answerer,
+                    answerer_nickname: answerer_nickname.clone(),
+                    scores: scores.iter().cloned().collect(),
+                });
+            }
+            GameEvent::FinalScores(scores) => {
+                self.broadcast(&ServerMessage::FinalScores {
+                    scores: scores.clone(),
+                });
+            }
+            // No client-facing analog; these only matter to a frontend
+            // that paces a single shared screen.
+            GameEvent::QuestionsCollected
+            | GameEvent::AnswersCollected
+            | GameEvent::GuessResult { .. } => {}
+        }
+    }
+}
+
+/// Listens on `port`, waits for `num_players` clients to each send a
+/// `ClientHello::Join`, then runs a full game fanning prompts out to them
+/// with the given `scoring` rules and bluff/decoy mode setting.
+pub fn run_server(port: u16, num_players: usize, scoring: ScoringRules, decoy_mode: bool) -> io::Result<()> {
+    let listener = TcpListener::bind(("0.0.0.0", port))?;
+    println!("=> Listening on port {}, waiting for {} players...", port, num_players);
+
+    let mut joined = Vec::with_capacity(num_players);
+    while joined.len() < num_players {
+        let (stream, addr) = listener.accept()?;
+        let mut conn = Connection::new(stream)?;
+        let nickname = match conn.recv_hello() {
+            Ok(ClientHello::Join { nickname }) => nickname,
+            Err(e) => {
+                eprintln!("=> rejecting connection from {}: {}", addr, e);
+                continue;
+            }
+        };
+        println!("=> {} joined from {}", nickname, addr);
+        joined.push((nickname, conn));
+    }
+
+    // Player IDs are assigned in join order, matching the order
+    // `Game::add_new_player` hands them out below.
+    let mut connections = HashMap::new();
+    let mut game = Game::new(ServerFrontend {
+        connections: HashMap::new(),
+    });
+    game.set_scoring_rules(scoring);
+    game.set_decoy_mode(decoy_mode);
+    for (id, (nickname, conn)) in joined.into_iter().enumerate() {
+        game.add_new_player(nickname);
+        connections.insert(id as PlayerID, conn);
+    }
+    game.frontend_mut().connections = connections;
+
+    game.start();
+    Ok(())
+}